@@ -0,0 +1,64 @@
+//! The exponential distribution (rate 1), sampled via the ziggurat method.
+
+use std::sync::OnceLock;
+
+use crate::RandomSource;
+
+use super::ziggurat::{self, uniform01, ZigguratTables};
+
+/// `e^{-x}` on `[0, infinity)`.
+fn pdf(x: f64) -> f64 {
+    (-x).exp()
+}
+
+fn tables() -> &'static ZigguratTables {
+    static TABLES: OnceLock<ZigguratTables> = OnceLock::new();
+    TABLES.get_or_init(|| ZigguratTables::build(pdf))
+}
+
+/// The exponential distribution with rate 1.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Exp;
+
+impl Exp {
+    /// Draws one sample using the ziggurat method, falling back to
+    /// tail/wedge rejection outside the fast rectangular region.
+    pub fn sample(&self, source: &mut impl RandomSource) -> f64 {
+        let tables = tables();
+        ziggurat::sample(source, tables, false, |source, layer, x| {
+            if layer == 0 {
+                // The exponential distribution is memoryless, so the
+                // excess past the tail boundary is itself Exp(1).
+                Some(tables.x[1] - uniform01(source).ln())
+            } else {
+                let f_outer = tables.f[layer];
+                let f_inner = tables.f[layer + 1];
+                let y = f_outer + uniform01(source) * (f_inner - f_outer);
+                (y < pdf(x)).then_some(x)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SplitMix64;
+
+    #[test]
+    fn sample_mean_and_variance_match_exp_rate_one() {
+        let mut rng = SplitMix64::from_seed(7);
+        let exp = Exp;
+        const N: usize = 200_000;
+
+        let samples: Vec<f64> = (0..N).map(|_| exp.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+
+        let mean: f64 = samples.iter().sum::<f64>() / N as f64;
+        let variance: f64 = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / N as f64;
+
+        // Exp(1) has mean 1 and variance 1.
+        assert!((mean - 1.0).abs() < 0.02, "mean = {mean}");
+        assert!((variance - 1.0).abs() < 0.05, "variance = {variance}");
+    }
+}
@@ -0,0 +1,14 @@
+//! Sampling from non-uniform distributions on top of a [`RandomSource`].
+//!
+//! Each distribution exposes a `sample` method that draws from a generic
+//! `RandomSource`, mirroring the shape of the classic `rand` distributions
+//! layer without pulling in its trait hierarchy.
+
+mod exp;
+mod normal;
+mod uniform;
+mod ziggurat;
+
+pub use exp::Exp;
+pub use normal::Normal;
+pub use uniform::Uniform;
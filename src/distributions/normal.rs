@@ -0,0 +1,73 @@
+//! The standard normal distribution, sampled via the ziggurat method.
+
+use std::sync::OnceLock;
+
+use crate::RandomSource;
+
+use super::ziggurat::{self, uniform01, ZigguratTables};
+
+/// `e^{-x^2/2}` on `[0, infinity)`, i.e. the positive half of the
+/// (unnormalized) standard normal density.
+fn pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp()
+}
+
+fn tables() -> &'static ZigguratTables {
+    static TABLES: OnceLock<ZigguratTables> = OnceLock::new();
+    TABLES.get_or_init(|| ZigguratTables::build(pdf))
+}
+
+/// Draws a value in the positive tail beyond the tail boundary `r`, using
+/// Marsaglia's rejection pair: keep exponential candidates `(x, y)` until
+/// they fall under the Gaussian tail curve.
+fn sample_tail(source: &mut dyn RandomSource, r: f64) -> f64 {
+    loop {
+        let x = -uniform01(source).ln() / r;
+        let y = -uniform01(source).ln();
+        if 2.0 * y > x * x {
+            return r + x;
+        }
+    }
+}
+
+/// The standard normal distribution `N(0, 1)`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Normal;
+
+impl Normal {
+    /// Draws one sample from `N(0, 1)` using the ziggurat method, falling
+    /// back to tail/wedge rejection outside the fast rectangular region.
+    pub fn sample(&self, source: &mut impl RandomSource) -> f64 {
+        let tables = tables();
+        ziggurat::sample(source, tables, true, |source, layer, x| {
+            if layer == 0 {
+                Some(sample_tail(source, tables.x[1]))
+            } else {
+                let f_outer = tables.f[layer];
+                let f_inner = tables.f[layer + 1];
+                let y = f_outer + uniform01(source) * (f_inner - f_outer);
+                (y < pdf(x)).then_some(x)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SplitMix64;
+
+    #[test]
+    fn sample_mean_and_variance_match_standard_normal() {
+        let mut rng = SplitMix64::from_seed(42);
+        let normal = Normal;
+        const N: usize = 200_000;
+
+        let samples: Vec<f64> = (0..N).map(|_| normal.sample(&mut rng)).collect();
+        let mean: f64 = samples.iter().sum::<f64>() / N as f64;
+        let variance: f64 = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / N as f64;
+
+        assert!(mean.abs() < 0.02, "mean = {mean}");
+        assert!((variance - 1.0).abs() < 0.05, "variance = {variance}");
+    }
+}
@@ -0,0 +1,108 @@
+use crate::RandomSource;
+
+/// A uniform distribution over the half-open range `[low, high)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Uniform<T> {
+    low: T,
+    high: T,
+}
+
+impl Uniform<u64> {
+    /// Creates a uniform distribution over `[low, high)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`.
+    pub fn new(low: u64, high: u64) -> Self {
+        assert!(low < high, "Uniform::new requires low < high");
+        Self { low, high }
+    }
+
+    /// Draws an unbiased integer in `[low, high)`.
+    ///
+    /// Uses rejection sampling: draws outside the largest multiple of
+    /// `range` that fits in a `u64` are discarded, since keeping them would
+    /// bias the low end of the range via the modulo.
+    pub fn sample(&self, source: &mut impl RandomSource) -> u64 {
+        let range = self.high - self.low;
+        let limit = u64::MAX - (u64::MAX % range);
+        loop {
+            let value = source.next_u64();
+            if value < limit {
+                return self.low + value % range;
+            }
+        }
+    }
+}
+
+impl Uniform<f64> {
+    /// Creates a uniform distribution over `[low, high)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`.
+    pub fn new(low: f64, high: f64) -> Self {
+        assert!(low < high, "Uniform::new requires low < high");
+        Self { low, high }
+    }
+
+    /// Draws a value in `[low, high)`, using the top 53 bits of a `u64`
+    /// draw to fill an `f64`'s mantissa.
+    pub fn sample(&self, source: &mut impl RandomSource) -> f64 {
+        let bits = source.next_u64() >> 11;
+        let unit = bits as f64 * (1.0 / (1u64 << 53) as f64);
+        self.low + unit * (self.high - self.low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SplitMix64;
+
+    #[test]
+    fn u64_samples_are_roughly_evenly_distributed_over_a_small_range() {
+        let mut rng = SplitMix64::from_seed(11);
+        let uniform = Uniform::<u64>::new(0, 7);
+        const N: usize = 70_000;
+
+        let mut buckets = [0usize; 7];
+        for _ in 0..N {
+            let value = uniform.sample(&mut rng);
+            assert!(value < 7);
+            buckets[value as usize] += 1;
+        }
+
+        // Expected count per bucket is N / 7 ~ 10_000; allow generous slack.
+        for (bucket, count) in buckets.iter().enumerate() {
+            assert!(
+                (4_000..16_000).contains(count),
+                "bucket {bucket} had {count} hits"
+            );
+        }
+    }
+
+    #[test]
+    fn u64_sample_never_reaches_high_near_u64_max() {
+        let mut rng = SplitMix64::from_seed(22);
+        // Near the top of the u64 range, so almost every raw draw is kept
+        // by the rejection threshold and `low + value % range` is exercised
+        // right at the boundary.
+        let uniform = Uniform::<u64>::new(u64::MAX - 3, u64::MAX);
+        for _ in 0..10_000 {
+            let value = uniform.sample(&mut rng);
+            assert!(value < u64::MAX, "sample returned high: {value}");
+            assert!(value >= u64::MAX - 3);
+        }
+    }
+
+    #[test]
+    fn f64_samples_stay_within_bounds() {
+        let mut rng = SplitMix64::from_seed(33);
+        let uniform = Uniform::<f64>::new(-2.5, 10.0);
+        for _ in 0..10_000 {
+            let value = uniform.sample(&mut rng);
+            assert!((-2.5..10.0).contains(&value), "sample out of bounds: {value}");
+        }
+    }
+}
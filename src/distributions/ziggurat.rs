@@ -0,0 +1,209 @@
+//! Shared machinery for the ziggurat algorithm used by [`super::Normal`]
+//! and [`super::Exp`]: 256 layers of equal area covering a monotonically
+//! decreasing density, with a fast rectangular accept and a fallback for
+//! samples that land in the tail or the wedge between two layers.
+
+use crate::RandomSource;
+
+pub(crate) const LAYERS: usize = 256;
+
+/// How close to zero `x[LAYERS]` must land to count as "reached the
+/// origin" in [`solve_tail_boundary`]'s bisection.
+const EPSILON: f64 = 1e-9;
+
+/// Bisection steps for both the outer tail-boundary search and each inner
+/// layer inversion. `f64` has ~52 bits of mantissa, so root-finding over
+/// an `O(10)`-wide interval is fully converged well before this count;
+/// it's not tuned any tighter than that because table construction only
+/// runs once per process (cached behind a `OnceLock`), not per sample.
+const BISECTION_STEPS: u32 = 64;
+
+/// Layer boundaries (`x[i]`) and densities (`f[i]`) for one
+/// ziggurat-distributed density. `x` is decreasing in `i`: `x[0]` is the
+/// widest (bottom, tail-adjacent) boundary and `x[LAYERS]` is, by
+/// construction, the peak at the origin.
+pub(crate) struct ZigguratTables {
+    pub x: [f64; LAYERS + 1],
+    pub f: [f64; LAYERS + 1],
+}
+
+impl ZigguratTables {
+    /// Builds equal-area tables for a monotonically decreasing density
+    /// `pdf` on `[0, infinity)`.
+    ///
+    /// The tail boundary `x[1] = r` (and with it the common layer area `v
+    /// = r * pdf(r) + tail_area(r)`) is chosen, by an outer bisection, so
+    /// that recursively inverting `pdf` against each previous,
+    /// already-known (wider) boundary for `LAYERS` steps lands exactly
+    /// back on the origin. A `v` picked any other way — e.g. dividing the
+    /// total area under the curve by `LAYERS` — over- or under-shoots the
+    /// origin, since the layers (bar the tail) are rectangles that
+    /// overhang the true curve and so cover strictly more area than the
+    /// curve itself.
+    pub fn build(pdf: impl Fn(f64) -> f64) -> Self {
+        solve_tail_boundary(&pdf).1
+    }
+}
+
+/// Builds the full layer tables for a given tail boundary `r`, alongside
+/// whether the construction actually reached the origin: `Ok(())` if
+/// `x[LAYERS]` landed within [`EPSILON`] of zero, `Err(true)` if a layer
+/// ran out of room before the origin (`r` too small, `v` too large), or
+/// `Err(false)` if layers remained unused once `x` hit zero (`r` too
+/// large, `v` too small). [`solve_tail_boundary`] and
+/// [`ZigguratTables::build`] both funnel through this single construction
+/// so the two can never drift apart.
+fn construct(r: f64, pdf: &impl Fn(f64) -> f64) -> (ZigguratTables, Result<(), bool>) {
+    let v = r * pdf(r) + tail_area(r, pdf);
+
+    let mut x = [0.0; LAYERS + 1];
+    let mut f = [0.0; LAYERS + 1];
+
+    x[1] = r;
+    f[1] = pdf(r);
+    x[0] = v / f[1];
+    f[0] = pdf(x[0]);
+
+    let mut status = Err(false);
+    for i in 2..=LAYERS {
+        match invert_pdf_above(v, x[i - 1], f[i - 1], pdf) {
+            Some(xi) => {
+                x[i] = xi;
+                f[i] = pdf(xi);
+            }
+            None => {
+                status = Err(true);
+                break;
+            }
+        }
+        if i == LAYERS {
+            status = if x[i] < EPSILON { Ok(()) } else { Err(false) };
+        }
+    }
+
+    (ZigguratTables { x, f }, status)
+}
+
+/// Finds `x[i]` in `(0, x_prev)` such that the rectangle of width
+/// `x_prev` (the already-known, wider boundary) and height `pdf(x[i]) -
+/// f_prev` has area `v`, i.e. `pdf(x[i]) == f_prev + v / x_prev`. `pdf`
+/// is monotonically decreasing, so this is a straightforward bisection.
+///
+/// Returns `None` if the target density would have to exceed `pdf(0)` to
+/// balance the area — i.e. the layers below have already reached the
+/// origin and there is nothing left to invert.
+fn invert_pdf_above(v: f64, x_prev: f64, f_prev: f64, pdf: &impl Fn(f64) -> f64) -> Option<f64> {
+    let target = f_prev + v / x_prev;
+    if target >= pdf(0.0) {
+        return None;
+    }
+
+    let mut lo = 0.0f64;
+    let mut hi = x_prev;
+    for _ in 0..BISECTION_STEPS {
+        let mid = 0.5 * (lo + hi);
+        if pdf(mid) > target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(0.5 * (lo + hi))
+}
+
+/// Finds the tail boundary `r` such that [`construct`]ing the full layer
+/// table from it lands exactly on the origin: too large an `r` leaves
+/// `x[LAYERS]` short of the origin after all layers are used (the layers
+/// were too thin), and too small an `r` overshoots it (the layers were
+/// too thick and ran out before using them all). Returns `r` and the
+/// tables built from it, so callers don't need to re-run [`construct`].
+fn solve_tail_boundary(pdf: &impl Fn(f64) -> f64) -> (f64, ZigguratTables) {
+    let mut lo = 1e-6f64;
+    let mut hi = 40.0f64;
+    let mut tables = construct(0.5 * (lo + hi), pdf).0;
+    for _ in 0..BISECTION_STEPS {
+        let r = 0.5 * (lo + hi);
+        let (built, status) = construct(r, pdf);
+        tables = built;
+
+        match status {
+            // Layers were too thick: r was too small, so v was too large.
+            Err(true) => lo = r,
+            // Layers were too thin: didn't reach the origin in time.
+            Err(false) => hi = r,
+            Ok(()) => return (r, tables),
+        }
+    }
+    (0.5 * (lo + hi), tables)
+}
+
+/// Integrates `pdf` from `r` to effective infinity via Simpson's rule.
+/// Every density used here decays to nothing well before `UPPER`, so a
+/// fixed upper bound is accurate enough.
+fn tail_area(r: f64, pdf: &impl Fn(f64) -> f64) -> f64 {
+    const UPPER: f64 = 40.0;
+    const STEPS: usize = 1000;
+
+    if r >= UPPER {
+        return 0.0;
+    }
+
+    let h = (UPPER - r) / STEPS as f64;
+    let mut sum = pdf(r) + pdf(UPPER);
+    for i in 1..STEPS {
+        let x = r + i as f64 * h;
+        sum += pdf(x) * if i % 2 == 0 { 2.0 } else { 4.0 };
+    }
+    sum * h / 3.0
+}
+
+/// Draws a uniform `f64` in `[0, 1)` from a full `u64` of entropy.
+pub(crate) fn uniform01(source: &mut dyn RandomSource) -> f64 {
+    (source.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+/// Runs the ziggurat algorithm: pick a random layer and a uniform point
+/// within it, accept immediately in the common rectangular case, and defer
+/// to `resolve_edge_case` for points that land in the tail (layer 0) or
+/// the thin wedge between two layers.
+pub(crate) fn sample(
+    source: &mut impl RandomSource,
+    tables: &ZigguratTables,
+    symmetric: bool,
+    resolve_edge_case: impl Fn(&mut dyn RandomSource, usize, f64) -> Option<f64>,
+) -> f64 {
+    loop {
+        let bits = source.next_u64();
+        let layer = (bits & 0xFF) as usize;
+        let u = (bits >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
+        let magnitude = u * tables.x[layer];
+
+        let accepted = if magnitude < tables.x[layer + 1] {
+            Some(magnitude)
+        } else {
+            resolve_edge_case(source, layer, magnitude)
+        };
+
+        if let Some(value) = accepted {
+            let negative = symmetric && (bits >> 8) & 1 == 1;
+            return if negative { -value } else { value };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_area_construction_converges_to_the_origin() {
+        // e^{-x}: a minimal sanity check that the shared construction
+        // actually closes up at the peak for a simple density,
+        // independent of Normal/Exp's own statistical tests.
+        let tables = ZigguratTables::build(|x: f64| (-x).exp());
+        assert!(tables.x[LAYERS] < 1e-6, "x[LAYERS] = {}", tables.x[LAYERS]);
+        for i in 0..LAYERS {
+            assert!(tables.x[i] > tables.x[i + 1], "x not decreasing at layer {i}");
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! ISAAC, a cryptographically-oriented PRNG by Bob Jenkins.
+//!
+//! Large-period and reasonably strong, ISAAC can be seeded once from a
+//! hardware draw and then run for a long time without further hardware
+//! calls, unlike [`crate::HwRng`] which pays the RDSEED/RDRAND cost on
+//! every draw.
+
+use crate::hw;
+use crate::random_source::RandomSource;
+
+const LOG_SIZE: usize = 8;
+const RAND_SIZE: usize = 1 << LOG_SIZE;
+const GOLDEN_RATIO: u32 = 0x9E3779B9;
+
+/// An ISAAC generator.
+pub struct IsaacRng {
+    mem: [u32; RAND_SIZE],
+    rsl: [u32; RAND_SIZE],
+    a: u32,
+    b: u32,
+    c: u32,
+    index: usize,
+}
+
+impl IsaacRng {
+    /// Creates a generator seeded from a single hardware RDSEED draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the CPU has no usable hardware entropy source. Use
+    /// [`IsaacRng::from_seed`] to seed deterministically instead.
+    #[allow(clippy::new_without_default)] // `new` draws hardware entropy and can panic, unlike a typical Default.
+    pub fn new() -> Self {
+        let seed = hw::rdseed_u64().expect("no hardware entropy source (RDSEED) available to seed IsaacRng");
+        Self::from_seed(&seed.to_le_bytes())
+    }
+
+    /// Creates a generator from a seed slice, filling `mem` from it and
+    /// running ISAAC's standard golden-ratio initialization mix.
+    pub fn from_seed(seed: &[u8]) -> Self {
+        let mut mem = [0u32; RAND_SIZE];
+        for (word, chunk) in mem.iter_mut().zip(seed.chunks(4)) {
+            let mut buf = [0u8; 4];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            *word = u32::from_le_bytes(buf);
+        }
+
+        let mut rng = IsaacRng {
+            mem,
+            rsl: [0u32; RAND_SIZE],
+            a: 0,
+            b: 0,
+            c: 0,
+            index: RAND_SIZE,
+        };
+        rng.init();
+        rng
+    }
+
+    fn init(&mut self) {
+        let mut regs = [GOLDEN_RATIO; 8];
+
+        for _ in 0..4 {
+            mix(&mut regs);
+        }
+
+        // Two passes over `mem`: the first mixes the seed in, the second
+        // mixes the mixed-in result back through itself, per the reference
+        // implementation.
+        for _ in 0..2 {
+            for i in (0..RAND_SIZE).step_by(8) {
+                for (r, m) in regs.iter_mut().zip(&self.mem[i..i + 8]) {
+                    *r = r.wrapping_add(*m);
+                }
+                mix(&mut regs);
+                self.mem[i..i + 8].copy_from_slice(&regs);
+            }
+        }
+
+        self.a = 0;
+        self.b = 0;
+        self.c = 0;
+        self.index = RAND_SIZE;
+    }
+
+    /// Runs one full refill of the `rsl` results buffer.
+    fn isaac(&mut self) {
+        self.c = self.c.wrapping_add(1);
+        self.b = self.b.wrapping_add(self.c);
+
+        for i in 0..RAND_SIZE {
+            let x = self.mem[i];
+            self.a = match i % 4 {
+                0 => self.a ^ (self.a << 13),
+                1 => self.a ^ (self.a >> 6),
+                2 => self.a ^ (self.a << 2),
+                _ => self.a ^ (self.a >> 16),
+            };
+            self.a = self.a.wrapping_add(self.mem[(i + RAND_SIZE / 2) % RAND_SIZE]);
+
+            let y = self.mem[((x >> 2) as usize) & (RAND_SIZE - 1)]
+                .wrapping_add(self.a)
+                .wrapping_add(self.b);
+            self.mem[i] = y;
+
+            self.b = self.mem[((y >> (2 + LOG_SIZE)) as usize) & (RAND_SIZE - 1)].wrapping_add(x);
+            self.rsl[i] = self.b;
+        }
+
+        self.index = 0;
+    }
+
+    /// Draws the next 32-bit output word, refilling the results buffer
+    /// when exhausted and draining it in reverse.
+    fn next_u32(&mut self) -> u32 {
+        if self.index >= RAND_SIZE {
+            self.isaac();
+        }
+        let value = self.rsl[RAND_SIZE - 1 - self.index];
+        self.index += 1;
+        value
+    }
+}
+
+/// The four-way shift barrel used both to seed ISAAC's internal registers
+/// and to mix the seed into `mem` during initialization.
+fn mix(r: &mut [u32; 8]) {
+    r[0] ^= r[1] << 11;
+    r[3] = r[3].wrapping_add(r[0]);
+    r[1] = r[1].wrapping_add(r[2]);
+    r[1] ^= r[2] >> 2;
+    r[4] = r[4].wrapping_add(r[1]);
+    r[2] = r[2].wrapping_add(r[3]);
+    r[2] ^= r[3] << 8;
+    r[5] = r[5].wrapping_add(r[2]);
+    r[3] = r[3].wrapping_add(r[4]);
+    r[3] ^= r[4] >> 16;
+    r[6] = r[6].wrapping_add(r[3]);
+    r[4] = r[4].wrapping_add(r[5]);
+    r[4] ^= r[5] << 10;
+    r[7] = r[7].wrapping_add(r[4]);
+    r[5] = r[5].wrapping_add(r[6]);
+    r[5] ^= r[6] >> 4;
+    r[0] = r[0].wrapping_add(r[5]);
+    r[6] = r[6].wrapping_add(r[7]);
+    r[6] ^= r[7] << 8;
+    r[1] = r[1].wrapping_add(r[6]);
+    r[7] = r[7].wrapping_add(r[0]);
+    r[7] ^= r[0] >> 9;
+    r[2] = r[2].wrapping_add(r[7]);
+    r[0] = r[0].wrapping_add(r[1]);
+}
+
+impl RandomSource for IsaacRng {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        let mut chunks = bytes.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u32().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let value = self.next_u32().to_le_bytes();
+            remainder.copy_from_slice(&value[..remainder.len()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_reproduces_the_same_stream() {
+        let mut a = IsaacRng::from_seed(b"isaac test seed");
+        let mut b = IsaacRng::from_seed(b"isaac test seed");
+
+        let words_a: Vec<u32> = (0..16).map(|_| a.next_u32()).collect();
+        let words_b: Vec<u32> = (0..16).map(|_| b.next_u32()).collect();
+        assert_eq!(words_a, words_b);
+    }
+
+    #[test]
+    fn different_seeds_diverge_and_output_is_not_degenerate() {
+        let mut a = IsaacRng::from_seed(b"seed one");
+        let mut b = IsaacRng::from_seed(b"seed two");
+
+        let words_a: Vec<u32> = (0..16).map(|_| a.next_u32()).collect();
+        let words_b: Vec<u32> = (0..16).map(|_| b.next_u32()).collect();
+        assert_ne!(words_a, words_b);
+        assert!(words_a.iter().any(|&w| w != 0));
+        assert!(words_a.iter().collect::<std::collections::HashSet<_>>().len() > 1);
+    }
+
+    #[test]
+    fn fill_bytes_handles_a_partial_final_chunk() {
+        let mut rng = IsaacRng::from_seed(b"partial chunk seed");
+        let mut buf = [0u8; 10]; // not a multiple of 4
+        rng.fill_bytes(&mut buf);
+        assert!(buf.iter().any(|&b| b != 0));
+    }
+}
@@ -0,0 +1,14 @@
+//! Hardware-seeded randomization utilities.
+
+pub mod distributions;
+pub mod hw;
+mod isaac;
+mod random_source;
+mod reseeding;
+mod splitmix64;
+
+pub use hw::HwRng;
+pub use isaac::IsaacRng;
+pub use random_source::{RandomSource, Reseed};
+pub use reseeding::ReseedingRng;
+pub use splitmix64::SplitMix64;
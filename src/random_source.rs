@@ -0,0 +1,27 @@
+//! The core abstraction every generator in this crate implements.
+
+/// A source of random bytes.
+///
+/// This is intentionally minimal: implementors only need to fill a byte
+/// slice, which lets callers write code against the trait and swap between
+/// hardware and software generators without touching call sites.
+pub trait RandomSource {
+    /// Fills `bytes` with random data.
+    fn fill_bytes(&mut self, bytes: &mut [u8]);
+
+    /// Draws a random `u64`. The default implementation assembles one from
+    /// [`RandomSource::fill_bytes`]; implementors with a native `u64` step
+    /// should override this to avoid the round-trip through a buffer.
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+}
+
+/// A [`RandomSource`] that can be re-keyed in place from fresh entropy,
+/// without being replaced or losing its position in a generic pipeline.
+pub trait Reseed {
+    /// Re-keys the generator from `seed`, discarding any buffered state.
+    fn reseed(&mut self, seed: u64);
+}
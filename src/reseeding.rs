@@ -0,0 +1,88 @@
+//! An auto-reseeding wrapper combining a fast software PRNG with a slower,
+//! higher-quality hardware entropy source.
+
+use crate::random_source::{RandomSource, Reseed};
+
+/// Re-key the inner generator after this many bytes have been produced.
+const DEFAULT_RESEED_THRESHOLD: usize = 32 * 1024;
+
+/// Wraps an inner PRNG `R`, periodically re-keying it from a (typically
+/// slower) entropy source `S` so callers get hardware-backed freshness
+/// without paying the hardware latency on every draw.
+pub struct ReseedingRng<R, S> {
+    inner: R,
+    source: S,
+    produced: usize,
+    threshold: usize,
+}
+
+impl<R, S> ReseedingRng<R, S>
+where
+    R: RandomSource + Reseed,
+    S: RandomSource,
+{
+    /// Wraps `inner`, reseeding it from `source` every
+    /// [`DEFAULT_RESEED_THRESHOLD`] bytes produced.
+    pub fn new(inner: R, source: S) -> Self {
+        Self::with_threshold(inner, source, DEFAULT_RESEED_THRESHOLD)
+    }
+
+    /// Wraps `inner`, reseeding it from `source` every `threshold` bytes
+    /// produced.
+    pub fn with_threshold(inner: R, source: S, threshold: usize) -> Self {
+        Self {
+            inner,
+            source,
+            produced: 0,
+            threshold,
+        }
+    }
+
+    fn reseed_if_due(&mut self) {
+        if self.produced >= self.threshold {
+            let seed = self.source.next_u64();
+            self.inner.reseed(seed);
+            self.produced = 0;
+        }
+    }
+}
+
+impl<R, S> RandomSource for ReseedingRng<R, S>
+where
+    R: RandomSource + Reseed,
+    S: RandomSource,
+{
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        self.reseed_if_due();
+        self.inner.fill_bytes(bytes);
+        self.produced += bytes.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SplitMix64;
+
+    #[test]
+    fn reseeds_once_the_threshold_is_crossed() {
+        let mut rng = ReseedingRng::with_threshold(SplitMix64::from_seed(1), SplitMix64::from_seed(2), 8);
+
+        let mut first = [0u8; 8];
+        rng.fill_bytes(&mut first);
+
+        let mut second = [0u8; 8];
+        rng.fill_bytes(&mut second);
+
+        // What the inner generator would have produced next had it never
+        // been reseeded.
+        let mut unreseeded = SplitMix64::from_seed(1);
+        let mut consumed = [0u8; 8];
+        unreseeded.fill_bytes(&mut consumed);
+        assert_eq!(consumed, first);
+        let mut would_have_continued = [0u8; 8];
+        unreseeded.fill_bytes(&mut would_have_continued);
+
+        assert_ne!(second, would_have_continued);
+    }
+}
@@ -0,0 +1,110 @@
+//! SplitMix64, a fast software PRNG for use when hardware RNG is
+//! unavailable or a deterministic, reproducible stream is needed.
+
+use crate::hw;
+use crate::random_source::{RandomSource, Reseed};
+
+const GOLDEN_GAMMA: u64 = 0x9E3779B97F4A7C15;
+
+/// A SplitMix64 generator.
+///
+/// Not cryptographically secure, but fast and well-distributed. Useful as
+/// a fallback when RDSEED is unavailable, or for seeding other generators.
+pub struct SplitMix64 {
+    state: u64,
+    cached_u32: Option<u32>,
+}
+
+impl SplitMix64 {
+    /// Creates a generator seeded from a single hardware RDSEED draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the CPU has no usable hardware entropy source. Use
+    /// [`SplitMix64::from_seed`] to seed deterministically instead.
+    #[allow(clippy::new_without_default)] // `new` draws hardware entropy and can panic, unlike a typical Default.
+    pub fn new() -> Self {
+        let seed = hw::rdseed_u64().expect("no hardware entropy source (RDSEED) available to seed SplitMix64");
+        Self::from_seed(seed)
+    }
+
+    /// Creates a generator from an explicit seed, for deterministic streams.
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            state: seed,
+            cached_u32: None,
+        }
+    }
+
+    fn step(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next 32-bit value, splitting each 64-bit draw across two
+    /// calls instead of discarding half of it.
+    pub fn next_u32(&mut self) -> u32 {
+        if let Some(cached) = self.cached_u32.take() {
+            return cached;
+        }
+        let value = self.step();
+        self.cached_u32 = Some((value >> 32) as u32);
+        value as u32
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        let mut chunks = bytes.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.step().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let value = self.step().to_le_bytes();
+            remainder.copy_from_slice(&value[..remainder.len()]);
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.step()
+    }
+}
+
+impl Reseed for SplitMix64 {
+    fn reseed(&mut self, seed: u64) {
+        self.state = seed;
+        self.cached_u32 = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_reference_sequence_for_seed_zero() {
+        // First three outputs of the reference SplitMix64 implementation
+        // seeded with 0.
+        let mut rng = SplitMix64::from_seed(0);
+        assert_eq!(rng.next_u64(), 0xE220A8397B1DCDAF);
+        assert_eq!(rng.next_u64(), 0x6E789E6AA1B965F4);
+        assert_eq!(rng.next_u64(), 0x06C45D188009454F);
+    }
+
+    #[test]
+    fn next_u32_splits_a_single_draw_across_two_calls() {
+        let mut rng = SplitMix64::from_seed(0);
+        let whole = {
+            let mut probe = SplitMix64::from_seed(0);
+            probe.next_u64()
+        };
+
+        let low = rng.next_u32();
+        let high = rng.next_u32();
+        assert_eq!(low as u64 | ((high as u64) << 32), whole);
+    }
+}